@@ -0,0 +1,15 @@
+// app/actions/announce.js
+// publishes an announcement to every subscriber
+
+import { response } from "@titanpl/native";
+
+export const announce = (req) => {
+  const { message } = req.body;
+  if (!message) {
+    return response.json({ error: "A message is required" }, { status: 400 });
+  }
+
+  t.shareContext.broadcast("announcements", { message, at: Date.now() });
+
+  return response.json({ announced: true });
+};
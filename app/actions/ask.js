@@ -0,0 +1,20 @@
+// app/actions/ask.js
+// LLM proxy action using the t.ai drift op
+
+import { response } from "@titanpl/native";
+
+export const ask = (req) => {
+  const { prompt } = req.body;
+  if (!prompt) {
+    return response.json({ error: "A prompt is required" }, { status: 400 });
+  }
+
+  // t.ai talks to any OpenAI-compatible endpoint. The API key comes from
+  // secrets (OPENAI_API_KEY) and tokens stream straight into the client
+  // response — no buffering and no fetch timeout problems on long answers.
+  return drift(t.ai.chat({
+    model: "gpt-4o-mini",
+    messages: [{ role: "user", content: prompt }],
+    stream: true
+  }));
+};
@@ -0,0 +1,17 @@
+// app/actions/backup.js
+// zipped download of everything in uploads/
+
+import { response } from "@titanpl/native";
+
+export const backup = (req) => {
+  // Paths are sandboxed to the project root; the archive is assembled
+  // in the drift handler, not in JS memory.
+  const zip = drift(t.zip.create(["uploads/**/*"]));
+
+  return response.binary(zip, {
+    headers: {
+      "content-type": "application/zip",
+      "content-disposition": "attachment; filename=\"uploads-backup.zip\""
+    }
+  });
+};
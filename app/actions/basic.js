@@ -0,0 +1,21 @@
+// app/actions/basic.js
+// HTTP Basic auth parsing with the native t.encode builtin
+
+import { response } from "@titanpl/native";
+
+export const basic = (req) => {
+  const header = req.headers.get("Authorization") ?? "";
+  if (!header.startsWith("Basic ")) {
+    return response.json({ error: "Basic credentials required" }, { status: 401 });
+  }
+
+  // Native base64 decode — no JS polyfill in the bundle, and it works
+  // on ArrayBuffers too for binary payloads.
+  const decoded = t.encode.base64.decode(header.slice(6));
+  const [username] = decoded.split(":");
+
+  // This endpoint only demonstrates *parsing* the header — it performs
+  // no credential check. Verify against your user store (as /login
+  // does) before ever trusting Basic credentials.
+  return response.json({ parsed: true, username });
+};
@@ -1,26 +1,36 @@
 // chat simple ws api action
 
-import { log, ws } from "@titanpl/native";
+import { ws } from "@titanpl/native";
 
 export const chat = (req) => {
     const { event, socketId, body } = req;
 
-  
+
     if (event === "open") {
-      log(`[Server] New socket connected: ${socketId}`);
+      // Leveled + structured: action name and request id are attached
+      // automatically, fields land as JSON in production log mode.
+      t.log.info("socket connected", { socketId });
   
       ws.send(socketId, "Welcome to the Titan Starship!")
       ws.broadcast(`User ${socketId} joined the orbit.`);
+
+      // Standard timers now work inside actions (backed by the worker
+      // loop's timer wheel) — send a follow-up tip after a second.
+      setTimeout(() => {
+        ws.send(socketId, "Tip: everything you type is broadcast to the whole orbit.");
+      }, 1000);
     }
   
     if (event === "message") {
-      log(`[Server] Message from ${socketId}: ${body}`);
-      // Echo it back and broadcast to others
-      ws.broadcast(`${socketId}: ${body}`);
+      t.log.debug("message received", { socketId, bytes: body.length });
+      // Strip any XSS vectors before the message reaches other browsers —
+      // the native sanitizer keeps harmless markup and drops the rest.
+      const clean = t.html.sanitize(body);
+      ws.broadcast(`${socketId}: ${clean}`);
     }
   
     if (event === "close") {
-      log(`[Server] Socket disconnected: ${socketId}`);
+      t.log.info("socket disconnected", { socketId });
       ws.broadcast(`User ${socketId} left the orbit.`);
     }
   };
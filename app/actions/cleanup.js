@@ -0,0 +1,22 @@
+// app/actions/cleanup.js
+// scheduled job: sweep abandoned partial writes out of uploads/
+// (finished avatars are kept forever; mirror downloads live in the
+// managed tempfile scratch dir and clean themselves up)
+
+import { log } from "@titanpl/native";
+
+export const cleanup = (req) => {
+  const files = drift(t.fs.glob("uploads/**/*.part"));
+  const cutoff = Date.now() - 24 * 60 * 60 * 1000;
+
+  let removed = 0;
+  for (const f of files) {
+    if (f.mtime < cutoff) {
+      drift(t.fs.remove(f.path));
+      removed++;
+    }
+  }
+
+  log(`[cleanup] removed ${removed} abandoned partial upload(s)`);
+  return { removed };
+};
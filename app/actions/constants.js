@@ -0,0 +1,6 @@
+// app/actions/constants.js
+// shared build constants (not an action — imported by other actions)
+
+export const NAME = "titanpl-ex";
+export const MAJOR = 1;
+export const MINOR = 0;
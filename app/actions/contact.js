@@ -0,0 +1,37 @@
+// app/actions/contact.js
+// contact form — sends a transactional email via the native SMTP drift op
+
+import { response } from "@titanpl/native";
+
+const contactSchema = {
+  type: "object",
+  required: ["email", "message"],
+  properties: {
+    email: { type: "string", format: "email" },
+    message: { type: "string", minLength: 1, maxLength: 5000 }
+  }
+};
+
+export const contact = (req) => {
+  // Schema validation up front — in particular, email must really be an
+  // email address, since the job uses it verbatim as a Reply-To header.
+  const errors = t.validate(contactSchema, req.body);
+  if (errors) {
+    return response.json(
+      { error: "Invalid contact data", details: errors },
+      { status: 422 }
+    );
+  }
+
+  const { email, message } = req.body;
+
+  // Delivery moved to a background job: the request returns immediately
+  // and SMTP hiccups are retried with backoff (then dead-lettered)
+  // instead of failing the user's submit.
+  drift(t.jobs.enqueue("sendContactEmail", { email, message }, {
+    retries: 5,
+    backoff: "exponential"
+  }));
+
+  return response.json({ queued: true });
+};
@@ -0,0 +1,13 @@
+// app/actions/currencies.js
+// config-style lookup — the drift result itself is cached
+
+export const currencies = (req) => {
+  // The currency list barely ever changes, so cache the drift result:
+  // identical ops within the TTL return the cached JSON without touching
+  // the network at all.
+  const list = drift(t.fetch("https://api.frankfurter.app/currencies"), {
+    cache: "10m"
+  });
+
+  return { count: Object.keys(list).length, currencies: list };
+};
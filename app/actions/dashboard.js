@@ -0,0 +1,28 @@
+// app/actions/dashboard.js
+// fan-out aggregation — batched drifts run concurrently
+
+import { response } from "@titanpl/native";
+import { db } from "../db/db.js";
+
+export const dashboard = (req) => {
+  // Monotonic stopwatch — immune to wall-clock jumps, unlike Date math.
+  const sw = t.time.stopwatch();
+  const conn = db();
+
+  // A batch drift runs all members in parallel (join_all under the
+  // hood), so this costs one round trip of the slowest dependency
+  // instead of the sum of all three.
+  const [userCount, rates, status] = drift([
+    conn.query("SELECT COUNT(*) AS n FROM users", []),
+    t.fetch("https://api.frankfurter.app/latest?from=USD"),
+    t.fetch("https://api.frankfurter.app/currencies")
+  ]);
+
+  return response.json({
+    users: userCount[0].n,
+    usdRates: rates.rates,
+    currencies: Object.keys(status).length,
+    generatedAt: t.time.now({ format: "rfc3339" }),
+    elapsedMs: sw.elapsedMs()
+  });
+};
@@ -0,0 +1,14 @@
+// app/actions/docs.js
+// renders the project README as an HTML docs page
+
+import { fs, response } from "@titanpl/native";
+
+export const docs = (req) => {
+  const md = fs.readFile("README.md");
+
+  // Native markdown rendering (pulldown-cmark) with sanitization on,
+  // since the source is repo-controlled but cheap insurance is cheap.
+  const html = t.markdown.toHtml(md, { sanitize: true });
+
+  return response.html(html);
+};
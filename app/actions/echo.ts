@@ -0,0 +1,12 @@
+// app/actions/echo.ts
+// TypeScript action — types are stripped natively, no build step needed
+
+interface EchoBody {
+  message?: string;
+}
+
+export const echo = (req: { body: EchoBody }) => {
+  const message: string = req.body.message ?? "nothing to echo";
+
+  return { echoed: message, length: message.length };
+};
@@ -0,0 +1,12 @@
+// app/actions/events.js
+// SSE pass-through proxy
+
+export const events = (req) => {
+  // With { stream: true } the upstream body is piped into the client
+  // response chunk by chunk as it arrives — nothing is buffered, so this
+  // works for SSE feeds and other long-lived streaming APIs.
+  return drift(t.fetch("https://stream.wikimedia.org/v2/stream/recentchange", {
+    stream: true,
+    headers: { accept: "text/event-stream" }
+  }));
+};
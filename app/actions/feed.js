@@ -0,0 +1,25 @@
+// app/actions/feed.js
+// RSS feed built with the native XML serializer
+
+import { response } from "@titanpl/native";
+
+export const feed = (req) => {
+  const xml = t.xml.stringify({
+    rss: {
+      "@version": "2.0",
+      channel: {
+        title: "TitanPL Example Feed",
+        link: "https://example.com",
+        description: "What's new aboard the Titan",
+        item: [
+          { title: "Titan 6 released", link: "https://example.com/titan-6" },
+          { title: "Drift ops explained", link: "https://example.com/drift" }
+        ]
+      }
+    }
+  });
+
+  return response.text(xml, {
+    headers: { "content-type": "application/rss+xml" }
+  });
+};
@@ -0,0 +1,19 @@
+// app/actions/files.js
+// file browser for the uploads directory
+
+import { response } from "@titanpl/native";
+
+export const files = (req) => {
+  // Globbing is sandboxed to the project root; entries come back with
+  // name, size and mtime so no per-file stat drifts are needed.
+  const images = drift(t.fs.glob("uploads/**/*.{png,jpg,jpeg,webp}"));
+
+  return response.json({
+    count: images.length,
+    files: images.map((f) => ({
+      name: f.name,
+      size: f.size,
+      modified: f.mtime
+    }))
+  });
+};
@@ -0,0 +1,20 @@
+// app/actions/geo.js
+// caller geolocation from the local MaxMind database
+
+import { response } from "@titanpl/native";
+
+export const geo = (req) => {
+  // Local database lookup — no external API call per request. Returns
+  // null when geoip.db isn't configured or the IP is unknown.
+  const location = t.geoip.lookup(req.ip);
+  if (!location) {
+    return response.json({ error: "Location unavailable" }, { status: 404 });
+  }
+
+  return response.json({
+    ip: req.ip,
+    country: location.country,
+    region: location.region,
+    city: location.city
+  });
+};
@@ -0,0 +1,14 @@
+// app/actions/greet.js
+// localized greeting — translations load from app/locales/ at startup
+
+export const greet = (req) => {
+  // Picks the best locale from the Accept-Language header, falling back
+  // to "en" when nothing matches.
+  const locale = t.i18n.negotiate(req, "en");
+  const name = req.query.name ?? "traveler";
+
+  return {
+    locale,
+    message: t.i18n.t("greeting", { name }, locale)
+  };
+};
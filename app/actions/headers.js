@@ -0,0 +1,15 @@
+// app/actions/headers.js
+// header inspection demo
+
+import { response } from "@titanpl/native";
+
+export const headers = (req) => {
+  return response.json({
+    // Single-value convenience lookup (case-insensitive)
+    userAgent: req.headers.get("User-Agent"),
+    // Repeated headers are no longer collapsed — getAll returns every
+    // value that arrived on the wire, e.g. multiple Cookie headers.
+    cookies: req.headers.getAll("Cookie"),
+    accept: req.headers.getAll("Accept")
+  });
+};
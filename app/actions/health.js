@@ -0,0 +1,17 @@
+// app/actions/health.js
+// static health check — fully resolved at startup
+
+const DEV = false;
+const MIN_ENGINE = 6;
+
+export const health = (req) => {
+  // Ternaries, logical and comparison operators over constants are now
+  // folded by the static analyzer, so this whole response is precomputed
+  // and served without ever entering V8.
+  return t.response.json({
+    status: "ok",
+    mode: DEV ? "development" : "production",
+    engineOk: MIN_ENGINE >= 6,
+    debug: DEV && "verbose"
+  });
+};
@@ -0,0 +1,6 @@
+// app/actions/hellov1.js
+// legacy v1 payload, kept for existing clients
+
+export const hellov1 = (req) => {
+  return { message: "hello" };
+};
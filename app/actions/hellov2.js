@@ -0,0 +1,6 @@
+// app/actions/hellov2.js
+// current payload shape — served for /v2/ paths and unversioned requests
+
+export const hellov2 = (req) => {
+  return { message: "hello", version: 2 };
+};
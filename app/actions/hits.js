@@ -0,0 +1,12 @@
+// app/actions/hits.js
+// cross-instance hit counter via the t.redis client
+
+export const hits = (req) => {
+  // Backed by the pooled Redis connection (REDIS_URI), so the count is
+  // shared across every instance of the app — unlike shareContext,
+  // which is per-process.
+  const count = drift(t.redis.incr("titanpl-ex:hits"));
+  drift(t.redis.expire("titanpl-ex:hits", 86400));
+
+  return { hits: count };
+};
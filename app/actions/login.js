@@ -12,6 +12,25 @@ import { db } from "../db/db.js";
 import bcrypt from "bcryptjs";
 
 export const login = (req) => {
+    // 0. Naive Rate Limiting (failed attempts only)
+    // The TTL makes the counter self-expiring — no reaper cron, no
+    // manual deletes; the key vanishes 60 seconds after the most
+    // recent *failed* attempt. Successful logins never count, so a
+    // busy NAT IP full of legitimate users is unaffected.
+    const rlKey = `login-rl:${req.ip}`;
+    const recordFailure = () => {
+        t.shareContext.set(rlKey, (t.shareContext.get(rlKey) ?? 0) + 1, { ttl: 60 });
+        // Aggregated in Rust and exported on the Prometheus endpoint —
+        // counted here so every failure path increments it.
+        t.metrics.increment("logins_failed", { method: "manual" });
+    };
+    if ((t.shareContext.get(rlKey) ?? 0) >= 10) {
+        return response.json(
+            { error: "Too many failed login attempts, slow down" },
+            { status: 429 }
+        );
+    }
+
     // 1. Manual Validation
     const { username, password } = req.body;
     if (!username || !password) {
@@ -29,6 +48,7 @@ export const login = (req) => {
     const rows = drift(conn.query(sql, [username]));
 
     if (!rows || rows.length === 0) {
+        recordFailure();
         return response.json(
             { error: "Manual authentication failed: User not found" },
             { status: 401 }
@@ -40,12 +60,15 @@ export const login = (req) => {
     // 4. Manual Password Verification (Bcrypt)
     const isValid = bcrypt.compareSync(password, user.password);
     if (!isValid) {
+        recordFailure();
         return response.json(
             { error: "Manual authentication failed: Invalid password" },
             { status: 401 }
         );
     }
 
+    t.metrics.increment("logins_succeeded", { method: "manual" });
+
     // 5. Manual Data Scrubbing
     delete user.password;
 
@@ -56,7 +79,7 @@ export const login = (req) => {
             username: user.username,
             email: user.email
         },
-        "jii", // Secret key
+        t.env.string("JWT_SECRET", "jii"), // Shared secret — see app/auth/config.js
         { expiresIn: "7d" }
     );
 
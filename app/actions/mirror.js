@@ -0,0 +1,34 @@
+// app/actions/mirror.js
+// mirrors a remote file to local disk without buffering it in JS
+
+import { response } from "@titanpl/native";
+
+export const mirror = (req) => {
+  const { url } = req.body;
+  if (!url) {
+    return response.json({ error: "A url is required" }, { status: 400 });
+  }
+
+  // Managed scratch path: the server cleans it up when the request
+  // completes (and sweeps leftovers at startup), so aborted downloads
+  // can't slowly fill the disk.
+  const scratch = t.tempfile.create("bin");
+
+  // downloadTo streams the body straight to disk inside the tokio task;
+  // only the metadata crosses back into the isolate, so multi-hundred-MB
+  // files never exist as a JSON string.
+  const meta = drift(t.fetch(url, {
+    downloadTo: scratch.path,
+    timeout: 30000,
+    maxRedirects: 3,
+    // Outbound traffic can be routed through an egress proxy; leave
+    // HTTPS_PROXY unset to connect directly.
+    proxy: t.env.string("HTTPS_PROXY", "") || undefined
+  }));
+
+  return response.json({
+    status: meta.status,
+    size: meta.size,
+    path: meta.path
+  });
+};
@@ -0,0 +1,9 @@
+// app/actions/notifications.js
+// SSE stream of shareContext broadcasts
+
+export const notifications = (req) => {
+  // subscribe() integrates with the drift suspend mechanism: each
+  // published payload is delivered to this stream as an SSE event, and
+  // the subscription is torn down when the client disconnects.
+  return t.response.sse(t.shareContext.subscribe("announcements"));
+};
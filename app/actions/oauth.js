@@ -0,0 +1,50 @@
+// app/actions/oauth.js
+/**
+ * OAUTH / OIDC LOGIN
+ * Verifies a provider-issued RS256 ID token against the provider's JWKS
+ * (keys are fetched once and cached), then issues our own session token.
+ */
+
+import { response } from "@titanpl/native";
+
+export const oauth = (req) => {
+  const { idToken } = req.body;
+  if (!idToken) {
+    return response.json({ error: "An idToken is required" }, { status: 400 });
+  }
+
+  // Non-verifying decode first: inspect the header/claims to give a
+  // precise error before doing any key fetching.
+  const peek = t.jwt.decode(idToken);
+  if (!peek || peek.header.alg !== "RS256") {
+    return response.json({ error: "Unsupported token algorithm" }, { status: 401 });
+  }
+
+  // Algorithm pinning plus full claim validation: wrong audience,
+  // wrong issuer, or missing email all fail verification.
+  const claims = drift(t.jwt.verifyWithJwks(idToken, "https://www.googleapis.com/oauth2/v3/certs", {
+    algorithms: ["RS256"],
+    // Typed accessor with no default: a missing GOOGLE_CLIENT_ID fails
+    // loudly here instead of silently disabling audience validation.
+    audience: t.env.string("GOOGLE_CLIENT_ID"),
+    issuer: "https://accounts.google.com",
+    clockSkew: 30,
+    requiredClaims: ["email", "sub"]
+  }));
+
+  if (!claims) {
+    return response.json({ error: "Invalid provider token" }, { status: 401 });
+  }
+
+  // Downstream requests use our regular HS256 session token. The
+  // signing secret comes from env (falling back to the demo secret so
+  // the example still runs out of the box) and must match the IAuth
+  // config, or auth.guard() would reject these tokens.
+  const token = t.jwt.sign(
+    { sub: claims.sub, email: claims.email },
+    t.env.string("JWT_SECRET", "jii"),
+    { expiresIn: "7d" }
+  );
+
+  return response.json({ success: true, token, email: claims.email });
+};
@@ -0,0 +1,8 @@
+// app/actions/oldlogin.js
+// permanent redirect for the retired login path
+
+export const oldlogin = (req) => {
+  // Constant arguments, so the fast-path analyzer turns this into a
+  // precomputed 302 with a Location header — the isolate never runs.
+  return t.response.redirect("/login", 302);
+};
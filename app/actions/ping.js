@@ -0,0 +1,6 @@
+// app/actions/ping.js
+// single action serving multiple HTTP methods
+
+export const ping = (req) => {
+  return { pong: true, method: req.method };
+};
@@ -0,0 +1,23 @@
+// app/actions/prices.js
+// semi-dynamic endpoint served through the route-level cache
+
+import { response } from "@titanpl/native";
+
+export const prices = (req) => {
+  // Upstream lookup only runs on a cache miss or a background refresh.
+  // With stale-while-revalidate enabled on the route, clients always get
+  // an instant answer while a single refresh runs behind the scenes.
+  // Bounded by a per-attempt timeout with two retries: a slow upstream
+  // surfaces as a structured timeout error instead of hanging the request.
+  const rates = drift(t.fetch("https://api.frankfurter.app/latest?from=USD"), {
+    timeout: 2000,
+    retries: 2,
+    backoff: "exponential"
+  });
+
+  return response.json({
+    base: "USD",
+    rates: rates.rates,
+    fetchedAt: rates.date
+  });
+};
@@ -0,0 +1,12 @@
+// app/actions/profile.js
+// server-rendered profile page via the native template engine
+
+import { auth } from "../auth/config.js";
+
+export const profile = (req) => {
+  const user = auth.guard(req);
+
+  // Templates load from app/templates/ at startup (cached, with
+  // dev-mode reload) and render natively — no JS template library.
+  return t.render("profile.html", user);
+};
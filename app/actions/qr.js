@@ -0,0 +1,20 @@
+// app/actions/qr.js
+// QR code for 2FA enrollment — scan it with any authenticator app
+
+import { response } from "@titanpl/native";
+import { auth } from "../auth/config.js";
+
+export const qr = (req) => {
+  const user = auth.guard(req);
+
+  const secret = drift(t.kv.get(`totp:${user.id}`));
+  if (!secret) {
+    return response.json({ error: "Run /2fa/setup first" }, { status: 400 });
+  }
+
+  const uri = t.totp.uri(secret, { issuer: "titanpl-ex", account: user.username });
+
+  return response.binary(t.qr.png(uri, { size: 256 }), {
+    headers: { "content-type": "image/png" }
+  });
+};
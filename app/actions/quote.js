@@ -0,0 +1,18 @@
+// app/actions/quote.js
+// hot read-mostly caching with the native in-process LRU
+
+export const quote = (req) => {
+  // t.cache is a concurrent LRU in Rust (TTL + max-entries eviction),
+  // distinct from shareContext — tuned specifically for this pattern.
+  const cached = t.cache.get("quote:zen");
+  if (cached) {
+    return { quote: cached, cached: true };
+  }
+
+  const fresh = drift(t.fetch("https://zenquotes.io/api/random"));
+  const text = fresh[0].q;
+
+  t.cache.set("quote:zen", text, { ttl: "60s" });
+
+  return { quote: text, cached: false };
+};
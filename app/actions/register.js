@@ -0,0 +1,56 @@
+// app/actions/register.js
+// user registration with modern password hashing
+
+import { response } from "@titanpl/native";
+import { db } from "../db/db.js";
+
+const registerSchema = {
+  type: "object",
+  required: ["username", "email", "password"],
+  properties: {
+    username: { type: "string", minLength: 3, maxLength: 32 },
+    email: { type: "string", format: "email" },
+    password: { type: "string", minLength: 8 }
+  }
+};
+
+export const register = (req) => {
+  // Compiled once and cached; returns null when the body is valid.
+  const errors = t.validate(registerSchema, req.body);
+  if (errors) {
+    return response.json({ error: "Invalid registration data", details: errors }, { status: 422 });
+  }
+
+  const { username, email, password } = req.body;
+
+  // Argon2id with explicit parameters — new accounts get modern hashes,
+  // while bcrypt hashes from older rows still verify. Use
+  // t.password.needsRehash(hash) at login time to migrate them over.
+  const hash = t.password.hash(password, {
+    algo: "argon2id",
+    memory: 19456,
+    iterations: 2
+  });
+
+  const conn = db();
+  let rows;
+  try {
+    rows = drift(conn.query(
+      "INSERT INTO users (username, email, password) VALUES ($1, $2, $3) RETURNING id, username, email",
+      [username, email, hash]
+    ));
+  } catch (err) {
+    // Unique-constraint violation: a taken username/email is a normal
+    // client error, not a 500. Postgres reports SQLSTATE 23505; the
+    // SQLite dev fallback reports SQLITE_CONSTRAINT_UNIQUE.
+    if (err.code === "23505" || err.code === "SQLITE_CONSTRAINT_UNIQUE") {
+      return response.json(
+        { error: "Username or email is already taken" },
+        { status: 409 }
+      );
+    }
+    throw err;
+  }
+
+  return response.json({ success: true, user: rows[0] }, { status: 201 });
+};
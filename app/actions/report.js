@@ -0,0 +1,20 @@
+// app/actions/report.js
+// heavy report generation — runs on the dedicated "heavy" worker pool
+
+import { response } from "@titanpl/native";
+import { db } from "../db/db.js";
+
+export const report = (req) => {
+  const conn = db();
+  const users = drift(conn.query("SELECT id, username, email FROM users ORDER BY id", []));
+
+  // CPU-heavy aggregation like this is exactly what the pool isolation
+  // is for: it can saturate its own workers without starving /login.
+  const byDomain = {};
+  for (const u of users) {
+    const domain = u.email.split("@")[1] ?? "unknown";
+    byDomain[domain] = (byDomain[domain] ?? 0) + 1;
+  }
+
+  return response.json({ total: users.length, byDomain });
+};
@@ -0,0 +1,13 @@
+// app/actions/resolve.js
+// short-link resolver
+
+import { response } from "@titanpl/native";
+
+export const resolve = (req) => {
+  const target = drift(t.kv.get(`short:${req.params.code}`));
+  if (!target) {
+    return response.json({ error: "Unknown short link" }, { status: 404 });
+  }
+
+  return t.response.redirect(target, 302);
+};
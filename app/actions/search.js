@@ -0,0 +1,21 @@
+// app/actions/search.js
+// search endpoint with structured query parsing
+
+import { response } from "@titanpl/native";
+
+export const search = (req) => {
+  // Nested bracket syntax (?filter[domain]=gmail.com&filter[active]=1)
+  // parses into a real object — and values are properly URL-decoded.
+  const { q, filter } = t.querystring.parse(req.rawQuery, { nested: true });
+
+  // Repeated keys survive too: ?tag=a&tag=b arrives as ["a", "b"] on
+  // req.query instead of the last value winning.
+  const tags = [].concat(req.query.tag ?? []);
+
+  return response.json({
+    query: q ?? "",
+    filter: filter ?? {},
+    tags,
+    results: []
+  });
+};
@@ -0,0 +1,20 @@
+// app/actions/sendContactEmail.js
+// background job: deliver a contact form message
+
+export const sendContactEmail = (job) => {
+  const { email, message } = job.payload;
+
+  // Both fields are user input — sanitize before they land as HTML in
+  // the support inbox.
+  const safeEmail = t.html.sanitize(email);
+  const safeMessage = t.html.sanitize(message);
+
+  drift(t.email.send({
+    to: "support@example.com",
+    replyTo: email,
+    subject: "New contact form message",
+    html: `<p>From: ${safeEmail}</p><p>${safeMessage}</p>`
+  }));
+
+  return { delivered: true };
+};
@@ -0,0 +1,26 @@
+// app/actions/shorten.js
+// tiny URL shortener written against web-standard globals
+
+import { response } from "@titanpl/native";
+
+export const shorten = (req) => {
+  const { url } = req.body;
+
+  // URL, crypto.randomUUID and console.log are all available in actions
+  // now (console maps to t.log), so code written for other runtimes
+  // drops in unchanged.
+  let target;
+  try {
+    target = new URL(url);
+  } catch {
+    return response.json({ error: "A valid url is required" }, { status: 400 });
+  }
+
+  const code = crypto.randomUUID().slice(0, 8);
+  // t.kv is the embedded persistent store — unlike shareContext, short
+  // links survive a server restart without needing Postgres.
+  drift(t.kv.set(`short:${code}`, target.href));
+  console.log(`Shortened ${target.hostname} -> /s/${code}`);
+
+  return response.json({ code, short: `/s/${code}` });
+};
@@ -0,0 +1,8 @@
+// app/actions/status.js
+// bare object literal return — still a fast-path response
+
+export const status = (req) => {
+  // No t.response wrapper needed: the analyzer detects static return
+  // values of the action itself and serves them as precomputed JSON.
+  return { ok: true, service: "titanpl-ex" };
+};
@@ -0,0 +1,24 @@
+// app/actions/thumb.js
+// thumbnail generation for uploaded images
+
+import { response } from "@titanpl/native";
+
+export const thumb = (req) => {
+  const file = req.files?.image;
+  if (!file) {
+    return response.json({ error: "An image file is required" }, { status: 400 });
+  }
+
+  // Runs on the tokio blocking pool via a drift op — the isolate never
+  // touches the pixel data.
+  const png = drift(t.image.resize(file.data, {
+    width: 128,
+    height: 128,
+    fit: "cover",
+    format: "png"
+  }));
+
+  return response.binary(png, {
+    headers: { "content-type": "image/png" }
+  });
+};
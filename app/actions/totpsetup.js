@@ -0,0 +1,18 @@
+// app/actions/totpsetup.js
+// 2FA enrollment: issue a TOTP secret for the signed-in user
+
+import { response } from "@titanpl/native";
+import { auth } from "../auth/config.js";
+
+export const totpsetup = (req) => {
+  const user = auth.guard(req);
+
+  const secret = t.totp.generateSecret();
+  drift(t.kv.set(`totp:${user.id}`, secret));
+
+  return response.json({
+    secret,
+    // Feed this into any authenticator app (or the /qr endpoint).
+    uri: t.totp.uri(secret, { issuer: "titanpl-ex", account: user.username })
+  });
+};
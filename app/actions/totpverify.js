@@ -0,0 +1,22 @@
+// app/actions/totpverify.js
+// 2FA verification: check a 6-digit code against the stored secret
+
+import { response } from "@titanpl/native";
+import { auth } from "../auth/config.js";
+
+export const totpverify = (req) => {
+  const user = auth.guard(req);
+
+  const secret = drift(t.kv.get(`totp:${user.id}`));
+  if (!secret) {
+    return response.json({ error: "2FA is not set up for this account" }, { status: 400 });
+  }
+
+  // window: 1 tolerates one 30s step of clock drift either way.
+  const ok = t.totp.verify(secret, req.body.code, 1);
+  if (!ok) {
+    return response.json({ error: "Invalid code" }, { status: 401 });
+  }
+
+  return response.json({ verified: true });
+};
@@ -0,0 +1,24 @@
+// app/actions/track.js
+// analytics event ingestion — fire-and-forget into the message queue
+
+import { response } from "@titanpl/native";
+
+export const track = (req) => {
+  const { event, properties } = req.body;
+  if (!event) {
+    return response.json({ error: "An event name is required" }, { status: 400 });
+  }
+
+  // Publishes to the configured NATS/Kafka producer (QUEUE_URI); the
+  // rest of the system consumes these without any webhook round trips.
+  drift(t.queue.publish("analytics.events", {
+    // v7 UUIDs are time-ordered, so downstream consumers can sort and
+    // de-duplicate events without a separate timestamp index.
+    id: t.uuid.v7(),
+    event,
+    properties: properties ?? {},
+    at: Date.now()
+  }));
+
+  return response.json({ queued: true });
+};
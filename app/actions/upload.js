@@ -0,0 +1,29 @@
+// app/actions/upload.js
+// avatar upload action — safety checks run in the engine before this code
+
+import { response } from "@titanpl/native";
+
+export const upload = (req) => {
+  // By the time we get here, the route's upload policy has already
+  // enforced the size cap and sniffed the real MIME type from magic
+  // bytes, so req.files only ever contains approved images.
+  const file = req.files?.avatar;
+  if (!file) {
+    return response.json({ error: "An avatar file is required" }, { status: 400 });
+  }
+
+  // Never trust the client-supplied filename in a path — "../"-style
+  // names could escape uploads/. Generate the name server-side and take
+  // the extension from the sniffed MIME type, not the upload.
+  const ext = { "image/png": "png", "image/jpeg": "jpg", "image/webp": "webp" }[file.type];
+  const name = `${t.uuid.v4()}.${ext}`;
+  const saved = drift(t.fs.write(`uploads/${name}`, file.data));
+
+  return response.json({
+    success: true,
+    name,
+    originalName: file.name,
+    size: file.size,
+    path: saved.path
+  });
+};
@@ -0,0 +1,13 @@
+// app/actions/user.js
+// typed route parameter demo
+
+import { response } from "@titanpl/native";
+
+export const user = (req) => {
+  // The route declares :id<number>, so the engine has already rejected
+  // non-numeric ids with a 404 and req.params.id arrives as a number.
+  return response.json({
+    id: req.params.id,
+    type: typeof req.params.id
+  });
+};
@@ -0,0 +1,16 @@
+// app/actions/version.js
+// build info endpoint — pure built-in calls fold at analysis time
+
+import { NAME, MAJOR, MINOR } from "./constants.js";
+
+export const version = (req) => {
+  // Math.*, String methods and JSON.stringify over static arguments are
+  // computed by the analyzer, so bundler-emitted expressions like these
+  // no longer knock an action off the fast path. The analyzer also
+  // follows the relative import above, so cross-file constants fold too.
+  return t.response.json({
+    name: NAME.toUpperCase(),
+    version: `${MAJOR}.${Math.max(MINOR, 0)}`,
+    fingerprint: JSON.stringify({ name: NAME, major: MAJOR })
+  });
+};
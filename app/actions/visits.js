@@ -0,0 +1,11 @@
+// app/actions/visits.js
+// per-visitor counter using signed-cookie sessions
+
+export const visits = (req) => {
+  // req.session is loaded (and signature-checked) before the action
+  // runs; mutations are written back as a signed cookie automatically.
+  const count = (req.session.visits ?? 0) + 1;
+  t.session.set(req, { visits: count });
+
+  return { visits: count, firstVisit: count === 1 };
+};
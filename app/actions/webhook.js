@@ -0,0 +1,23 @@
+// app/actions/webhook.js
+// payment webhook receiver with explicit per-action secrets
+
+import { response } from "@titanpl/native";
+
+export const webhook = (req) => {
+  // req.env only contains what the route explicitly bound — no global
+  // env dump, so this action can't accidentally read DB credentials.
+  const { signingSecret } = req.env;
+
+  // Headers-like lookup: case-insensitive no matter how the sender
+  // capitalized the header on the wire.
+  const signature = req.headers.get("X-Webhook-Signature");
+
+  // Real HMAC verification via the native t.crypto builtin — no
+  // hand-rolled JS crypto, and the comparison is constant-time.
+  const expected = t.crypto.hmac("sha256", signingSecret, req.rawBody);
+  if (!signature || !t.crypto.timingSafeEqual(signature, expected)) {
+    return response.json({ error: "Invalid webhook signature" }, { status: 401 });
+  }
+
+  return response.json({ received: true });
+};
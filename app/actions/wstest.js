@@ -0,0 +1,9 @@
+// app/actions/wstest.js
+// serves the raw WebSocket test page
+
+export const wstest = (req) => {
+  // Constant path: the fast path loads the file once at startup, infers
+  // text/html, and serves the bytes without V8. A dynamic path here
+  // would fall back to a drift fs read instead.
+  return t.response.file("static/chat.html");
+};
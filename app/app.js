@@ -1,19 +1,181 @@
 // app/app.js
 import t from "@titanpl/route";
 
+// Fail fast at worker init if required secrets are missing — no more
+// discovering a blank env var on the first real request. (There is no
+// global env dump into JS anymore; use the typed t.env accessors.)
+t.env.require(["WEBHOOK_SECRET", "SESSION_SECRET"]);
+
 // 🛤️ Manual Login Route
 t.post("/login").action("login");
 
 // 🛡️ Official IAuth Secure Login Route
 t.post("/iauth-login").action("iauthlg");
 
+// 🌐 OAuth / OIDC Login (RS256 verified against the provider JWKS)
+t.post("/oauth-login").action("oauth");
+
+// 📝 Registration Route (Argon2id password hashing)
+t.post("/register").action("register");
+
+// 🔢 Two-Factor Auth (native TOTP)
+t.get("/2fa/setup").action("totpsetup");
+t.post("/2fa/verify").action("totpverify");
+t.get("/2fa/qr").action("qr");
+
 // User Context Route
 t.get("/me").action("me");
 
+// 🖼️ Server-Rendered Profile Page (t.render)
+t.get("/profile").action("profile");
+
+// 📚 Docs Page (native markdown rendering)
+t.get("/docs").action("docs");
+
+// Retired path — fast-path redirect, never touches V8
+t.post("/auth/login").action("oldlogin");
+
 t.ws("/chat").action("chat")
 
+// 🤖 LLM Proxy Route (t.ai streaming)
+t.post("/ask").action("ask");
+
+// 📡 SSE Pass-Through Proxy (streaming fetch)
+t.get("/events").action("events");
+
+// 🔔 Announcements (shareContext pub/sub over SSE)
+t.post("/announce").action("announce");
+t.get("/notifications").action("notifications");
+
+// 📤 Avatar Upload Route
+// The policy is enforced natively before the body ever reaches the action:
+// oversized bodies and spoofed content types are rejected with 413/415.
+t.post("/upload").action("upload").uploadPolicy({
+    maxSize: "5mb",
+    types: ["image/png", "image/jpeg", "image/webp"]
+});
+
+// 🖼️ Thumbnailing (native image ops on the blocking pool)
+t.post("/thumbnail").action("thumb").uploadPolicy({
+    maxSize: "10mb",
+    types: ["image/png", "image/jpeg", "image/webp"]
+});
+
+// 🗂️ Uploaded File Listing (sandboxed fs glob)
+t.get("/files").action("files");
+
+// 🗜️ Uploads Backup (zip drift op)
+t.get("/export/uploads.zip").action("backup").pool("heavy");
+
+// ✉️ Contact Form (native SMTP drift op)
+t.post("/contact").action("contact");
+
+// 📣 Analytics Ingestion (message queue publish)
+t.post("/track").action("track");
+
+// ⬇️ Remote File Mirror (streaming fetch-to-file)
+t.post("/mirror").action("mirror");
+
+// 🗣️ Localized Greeting (t.i18n + Accept-Language negotiation)
+t.get("/greet").action("greet");
+
+// 🌍 Caller Geolocation (local MaxMind lookup)
+t.get("/geo").action("geo");
+
+// 🔍 Search (structured query-string parsing)
+t.get("/search").action("search");
+
+// 🍪 Visit Counter (signed-cookie sessions)
+t.get("/visits").action("visits");
+
+// 💬 Quote of the Minute (native LRU cache, t.cache)
+t.get("/quote").action("quote");
+
+// 🔐 Basic Auth Demo (native t.encode base64)
+t.get("/basic").action("basic");
+
+// 🔗 URL Shortener (web-standard globals: URL, crypto, console)
+t.post("/shorten").action("shorten");
+t.get("/s/:code").action("resolve");
+
+// 🔢 Typed Route Parameters
+// :id<number> only matches numeric ids and the action receives a real number.
+// Also available: <uuid>, <slug>, <bool>, <date> and <re:...> custom patterns.
+// The action is pure over req.params, so hot ids are memoized between
+// the fast path and V8 — only the first hit per id runs the isolate.
+t.get("/users/:id<number>").action("user").memo({ ttl: "60s" });
+
+// 💱 Cached Rates Route (stale-while-revalidate)
+// Stale entries are served instantly while a single background
+// refresh keeps the cache warm.
+t.get("/prices").action("prices").cache({ ttl: "30s", staleWhileRevalidate: "5m" });
+
+// ❤️ Health Check (fast path, constants folded at startup)
+// High priority: load-balancer probes skip the queue behind batch bursts.
+t.get("/health").action("health").priority("high");
+
+// 🔴 Redis Hit Counter (shared across instances)
+t.get("/hits").action("hits");
+
+// 💶 Currency List (TTL-cached drift result)
+t.get("/currencies").action("currencies");
+
+// 📈 Dashboard Route (parallel batch drifts)
+t.get("/dashboard").action("dashboard");
+
+// 📰 RSS Feed (native t.xml; t.yaml is the same shape for YAML)
+t.get("/feed.xml").action("feed");
+
+// 🧮 CSV Export (native t.csv)
+t.get("/export/users.csv").action("exportusers");
+
+// 📊 Heavy Report Route (isolated on the "heavy" worker pool)
+t.get("/report").action("report").pool("heavy").priority("low");
+
+// 📄 Raw WS Test Page (t.response.file, fast-pathed static asset)
+t.get("/ws-test").action("wstest");
+
+// 🟦 TypeScript Action (types stripped by the engine, no build step)
+t.post("/echo").action("echo");
+
+// ✅ Status (fast path from a bare object literal)
+t.get("/status").action("status");
+
+// 🏷️ Build Info (fast path, built-in calls folded)
+t.get("/version").action("version");
+
+// 💬 Parameterized Reply Route
+// The :name substitution happens in Rust on a cached byte template,
+// so even with a parameter this never touches V8.
+t.get("/hello/:name").reply("Hello, :name! Welcome aboard the Titan.");
+
+// 🧾 Header Inspection Route (multi-value aware)
+t.get("/headers").action("headers");
+
+// 🔀 Versioned API Routes
+// Selected by the /v1 or /v2 path prefix or an Accept-Version header;
+// unversioned requests fall back to the latest version.
+t.get("/api/hello").action("hellov1").version(1);
+t.get("/api/hello").action("hellov2").version(2);
+
+// 🪝 Payment Webhook Route
+// Binds a single env-derived secret into req.env for this action only.
+t.post("/webhook").action("webhook").env({ signingSecret: "WEBHOOK_SECRET" });
+
+// 🏓 Multi-Method Route
+// One declaration covers both verbs; anything else gets a proper 405
+// with an Allow header listing the full set.
+t.route("/ping").methods(["GET", "POST"]).action("ping");
+
+// ⏰ Scheduled Jobs
+// Runs on the worker pool with overlap protection — a slow run never
+// stacks a second one on top.
+t.schedule("0 3 * * *", "cleanup");
+
 // Fallback Route
-t.get("/").action("home")
+// warmup(): run once per isolate with a synthetic request before the
+// listener binds, so JIT and lazy init happen ahead of real traffic.
+t.get("/").action("home").warmup()
 
 
 t.start(5100, "Titan Running on port 5100!");
@@ -3,13 +3,17 @@ import { db } from "../db/db.js";
 
 const dbConnection = db()
 export const auth = new IAuth({
-    secret: "jii",
-    
+    // Shared HS256 secret for every token issuer in the app (manual
+    // login, OAuth, IAuth itself). Set JWT_SECRET in production; the
+    // fallback keeps the demo runnable with zero setup.
+    secret: t.env.string("JWT_SECRET", "jii"),
+
+
     db: {
         conn: dbConnection,
         table: "users",
         identityField: "username",
         passwordField: "password",
-        scope: ["id", "username", "email", "avatar_url"] 
+        scope: ["id", "username", "email", "avatar_url", "created_at"]
     }
 })
\ No newline at end of file
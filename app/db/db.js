@@ -1,7 +1,10 @@
 // app/db/db.js (db connection)
 
 export const db = () => {
-    return t.db.connect(t.env.DB_URI, {
+    // Typed accessor with a default: falls back to an embedded SQLite
+    // file so the example runs with zero infrastructure in dev; point
+    // DB_URI at Postgres/MySQL in prod.
+    return t.db.connect(t.env.string("DB_URI", "sqlite://./dev.db"), {
         max: 15,
         min: 1,
         ssl: true
@@ -1,6 +1,8 @@
 -- app/db/login.sql (login query)
 
-SELECT id, username, email, password
+-- created_at decodes as an ISO-8601 string now that timestamp/uuid/jsonb
+-- columns are converted properly instead of coming back null.
+SELECT id, username, email, password, created_at
 FROM users
 WHERE username = $1
 LIMIT 1;
\ No newline at end of file
@@ -0,0 +1,37 @@
+// test/routes.test.js
+// end-to-end route tests via the @titanpl/test harness
+
+import { startApp, test, expect } from "@titanpl/test";
+
+// Boots the real engine on an ephemeral port against this project dir.
+// The secrets satisfy the t.env.require() check in app/app.js.
+const app = await startApp(".", {
+  env: {
+    WEBHOOK_SECRET: "test-webhook-secret",
+    SESSION_SECRET: "test-session-secret"
+  }
+});
+
+test("health check is served from the fast path", async () => {
+  const res = await app.get("/health");
+  expect(res.status).toBe(200);
+  expect(res.json().status).toBe("ok");
+  expect(res.headers.get("x-titan-fastpath")).toBe("hit");
+});
+
+test("typed params reject non-numeric ids", async () => {
+  expect((await app.get("/users/42")).status).toBe(200);
+  expect((await app.get("/users/abc")).status).toBe(404);
+});
+
+test("parameterized reply route substitutes in Rust", async () => {
+  const res = await app.get("/hello/astronaut");
+  expect(res.text()).toContain("Hello, astronaut!");
+});
+
+test("manual login rejects missing credentials", async () => {
+  const res = await app.post("/login", { body: {} });
+  expect(res.status).toBe(400);
+});
+
+await app.stop();